@@ -0,0 +1,37 @@
+use std::fmt;
+
+use crate::parse::ValueType;
+
+#[derive(Debug)]
+pub enum Error {
+    Message(String),
+    /// A value of `actual` kind was used where `context` (e.g. `/` or a
+    /// `let` binding) required `expected`.
+    TypeMismatch {
+        expected: ValueType,
+        actual: ValueType,
+        context: &'static str,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Message(message) => write!(f, "{message}"),
+            Error::TypeMismatch {
+                expected,
+                actual,
+                context,
+            } => write!(f, "expected {expected}, got {actual} in `{context}`"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[macro_export]
+macro_rules! bail {
+    ($($arg:tt)*) => {
+        fehler::throw!($crate::Error::Message(format!($($arg)*)))
+    };
+}
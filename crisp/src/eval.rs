@@ -1,17 +1,94 @@
 use crate::*;
+use fehler::throw;
 use std::collections::HashMap;
 
+// Numeric tower: `+`, `-` and `*` stay integer when every operand is an
+// integer, and promote to float as soon as one operand isn't.
+#[derive(Debug, Clone, Copy)]
+enum Num {
+    Int(i32),
+    Float(f64),
+}
+
+impl Num {
+    fn as_float(self) -> f64 {
+        match self {
+            Num::Int(it) => it as f64,
+            Num::Float(it) => it,
+        }
+    }
+}
+
+fn add(a: Num, b: Num) -> Num {
+    match (a, b) {
+        (Num::Int(a), Num::Int(b)) => Num::Int(a + b),
+        (a, b) => Num::Float(a.as_float() + b.as_float()),
+    }
+}
+
+fn sub(a: Num, b: Num) -> Num {
+    match (a, b) {
+        (Num::Int(a), Num::Int(b)) => Num::Int(a - b),
+        (a, b) => Num::Float(a.as_float() - b.as_float()),
+    }
+}
+
+fn mul(a: Num, b: Num) -> Num {
+    match (a, b) {
+        (Num::Int(a), Num::Int(b)) => Num::Int(a * b),
+        (a, b) => Num::Float(a.as_float() * b.as_float()),
+    }
+}
+
+// Division always yields a float, so `(/ 1 2)` is `0.5` rather than `0`.
+fn div(a: Num, b: Num) -> Num {
+    Num::Float(a.as_float() / b.as_float())
+}
+
 // Eval helpers
 #[throws]
-fn expr_to_number(expr: &Expr) -> i32 {
+fn expr_to_num(expr: &Expr, context: &'static str) -> Num {
+    match expr {
+        Expr::Constant(Atom::Number(it)) => Num::Int(*it),
+        Expr::Constant(Atom::Float(it)) => Num::Float(*it),
+        other => throw!(Error::TypeMismatch {
+            expected: ValueType::Number,
+            actual: type_of(other),
+            context,
+        }),
+    }
+}
+
+fn num_to_expr(number: Num) -> Expr {
+    match number {
+        Num::Int(it) => Expr::Constant(Atom::Number(it)),
+        Num::Float(it) => Expr::Constant(Atom::Float(it)),
+    }
+}
+
+// `=`/`!=` fall back to structural equality, but compare numerically
+// across the Int/Float boundary so `(= 2 2.0)` holds.
+fn expr_eq(a: &Expr, b: &Expr, context: &'static str) -> bool {
+    match (expr_to_num(a, context), expr_to_num(b, context)) {
+        (Ok(a), Ok(b)) => a.as_float() == b.as_float(),
+        _ => a == b,
+    }
+}
+
+#[throws]
+fn expr_to_string(expr: &Expr) -> String {
     match expr {
-        Expr::Constant(Atom::Number(it)) => *it,
-        _ => bail!("Invalid number passed: {}", expr),
+        Expr::Constant(Atom::String(it)) => it.clone(),
+        other => throw!(Error::TypeMismatch {
+            expected: ValueType::String,
+            actual: type_of(other),
+            context: "string",
+        }),
     }
 }
 
-fn number_to_expr(number: i32) -> Expr {
-    Expr::Constant(Atom::Number(number))
+fn string_to_expr(string: String) -> Expr {
+    Expr::Constant(Atom::String(string))
 }
 
 #[throws]
@@ -31,9 +108,9 @@ fn boolean_to_expr(boolean: bool) -> Expr {
 }
 
 #[throws]
-fn numbers(tail: &[Expr]) -> impl Iterator<Item = i32> {
+fn numbers(tail: &[Expr], context: &'static str) -> impl Iterator<Item = Num> {
     tail.iter()
-        .map(expr_to_number)
+        .map(|it| expr_to_num(it, context))
         .collect::<Result<Vec<_>, _>>()?
         .into_iter()
 }
@@ -82,14 +159,24 @@ fn curry(expr: Expr, left: &[Expr], right: &[Expr], marked: &mut [bool]) -> Expr
 }
 
 // Macros
+//
+// `?` inside the `map` closure propagates a `TypeMismatch` from
+// `expr_to_num` instead of the old `_ => false` arm swallowing it - a
+// comparison against the wrong type is an error, not a false result.
 macro_rules! logic {
-	($tail:ident => $a:ident $op:tt $b:ident) => {
-		boolean_to_expr($tail.windows(2).all(|it| match (&it[0], &it[1]) {
-            (Expr::Constant(Atom::Number($a)), Expr::Constant(Atom::Number($b))) => {
-                $a $op $b
-            }
-            _ => false,
-        }))
+	($tail:ident, $context:expr => $a:ident $op:tt $b:ident) => {
+		boolean_to_expr(
+            $tail
+                .windows(2)
+                .map(|it| -> Result<bool, Error> {
+                    let $a = expr_to_num(&it[0], $context)?.as_float();
+                    let $b = expr_to_num(&it[1], $context)?.as_float();
+                    Ok($a $op $b)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .all(|it| it),
+        )
 	};
 }
 
@@ -115,7 +202,11 @@ impl Context {
                                 let expr = self.eval(*item.1)?;
                                 self.environment.insert(name, expr);
                             }
-                            _ => bail!("Expected symbol, found following: {}", item.0),
+                            other => throw!(Error::TypeMismatch {
+                                expected: ValueType::Symbol,
+                                actual: type_of(&Expr::Constant(other)),
+                                context: "let binding",
+                            }),
                         }
                     }
                     return Ok(Expr::Nil);
@@ -156,38 +247,53 @@ impl Context {
                         }
                         Expr::Constant(Atom::BuiltIn(built_in)) => {
                             return Ok(match built_in {
-                                BuiltIn::Greater => logic!(tail => a > b),
-                                BuiltIn::Less => logic!(tail => a < b),
-                                BuiltIn::GreaterEqual => logic!(tail => a >= b),
-                                BuiltIn::LessEqual => logic!(tail => a <= b),
-                                BuiltIn::Plus => number_to_expr(numbers(&tail)?.sum()),
-                                BuiltIn::Minus => match car(&tail).map(expr_to_number) {
-                                    Some(Ok(car)) => number_to_expr(
-                                        numbers(cdr(&tail).unwrap_or_default())?
-                                            .fold(car, |a, b| a - b),
-                                    ),
-                                    _ => {
+                                BuiltIn::Greater => logic!(tail, ">" => a > b),
+                                BuiltIn::Less => logic!(tail, "<" => a < b),
+                                BuiltIn::GreaterEqual => logic!(tail, ">=" => a >= b),
+                                BuiltIn::LessEqual => logic!(tail, "<=" => a <= b),
+                                BuiltIn::Plus => {
+                                    num_to_expr(numbers(&tail, "+")?.fold(Num::Int(0), add))
+                                }
+                                // `car` is matched on its own (rather than
+                                // via `car(&tail).map(expr_to_num)`) so a
+                                // `TypeMismatch` from a wrongly-typed first
+                                // argument propagates via `?` instead of
+                                // being swallowed by the arity `bail!` below.
+                                BuiltIn::Minus => match car(&tail) {
+                                    Some(car) => {
+                                        let car = expr_to_num(car, "-")?;
+                                        num_to_expr(
+                                            numbers(cdr(&tail).unwrap_or_default(), "-")?
+                                                .fold(car, sub),
+                                        )
+                                    }
+                                    None => {
                                         bail!(
                                             "- expects one or more parameters, found {}",
                                             tail.len()
                                         )
                                     }
                                 },
-                                BuiltIn::Times => number_to_expr(numbers(&tail)?.product()),
+                                BuiltIn::Times => {
+                                    num_to_expr(numbers(&tail, "*")?.fold(Num::Int(1), mul))
+                                }
                                 BuiltIn::Equal => {
-                                    boolean_to_expr(tail.windows(2).all(|it| it[0] == it[1]))
+                                    boolean_to_expr(tail.windows(2).all(|it| expr_eq(&it[0], &it[1], "=")))
                                 }
                                 BuiltIn::NotEqual => {
-                                    boolean_to_expr(tail.windows(2).all(|it| it[0] != it[1]))
+                                    boolean_to_expr(tail.windows(2).all(|it| !expr_eq(&it[0], &it[1], "!=")))
                                 }
                                 BuiltIn::And => boolean_to_expr(booleans(&tail)?.all(|it| it)),
                                 BuiltIn::Or => boolean_to_expr(booleans(&tail)?.any(|it| it)),
-                                BuiltIn::Divide => match car(&tail).map(expr_to_number) {
-                                    Some(Ok(car)) => number_to_expr(
-                                        numbers(cdr(&tail).unwrap_or_default())?
-                                            .fold(car, |a, b| a / b),
-                                    ),
-                                    _ => bail!(
+                                BuiltIn::Divide => match car(&tail) {
+                                    Some(car) => {
+                                        let car = expr_to_num(car, "/")?;
+                                        num_to_expr(
+                                            numbers(cdr(&tail).unwrap_or_default(), "/")?
+                                                .fold(car, div),
+                                        )
+                                    }
+                                    None => bail!(
                                         "/ expects 1 or more parameters, found {}",
                                         tail.len()
                                     ),
@@ -196,6 +302,63 @@ impl Context {
                                     (true, Some(car)) => boolean_to_expr(!expr_to_boolean(car)?),
                                     _ => bail!("! expects 1 parameter, got {}", tail.len()),
                                 },
+                                BuiltIn::Concat => string_to_expr(
+                                    tail.iter()
+                                        .map(expr_to_string)
+                                        .collect::<Result<Vec<_>, _>>()?
+                                        .concat(),
+                                ),
+                                BuiltIn::Length => match car(&tail) {
+                                    Some(it) => num_to_expr(Num::Int(
+                                        expr_to_string(it)?.chars().count() as i32,
+                                    )),
+                                    None => bail!("length expects 1 parameter, got 0"),
+                                },
+                                // `car`/`get` are matched on their own (rather
+                                // than wrapped `.map(expr_to_string)`/`.map(expr_to_num)`
+                                // results) so a `TypeMismatch` from a wrongly-typed
+                                // argument propagates via `?` instead of being
+                                // swallowed by the arity `bail!` below. Indices are
+                                // also accepted as either `Num` variant - `(substring
+                                // "hi" 0.5 1)` truncates rather than erroring.
+                                BuiltIn::Substring => match (car(&tail), tail.get(1), tail.get(2)) {
+                                    (Some(string), Some(start), Some(end)) => {
+                                        let string = expr_to_string(string)?;
+                                        let start =
+                                            expr_to_num(start, "substring")?.as_float() as usize;
+                                        let end =
+                                            expr_to_num(end, "substring")?.as_float() as usize;
+                                        let chars = string.chars().collect::<Vec<_>>();
+                                        let start = start.min(chars.len());
+                                        let end = end.min(chars.len());
+                                        if start > end {
+                                            bail!(
+                                                "substring start {} is after end {}",
+                                                start,
+                                                end
+                                            );
+                                        }
+                                        string_to_expr(chars[start..end].iter().collect())
+                                    }
+                                    _ => bail!(
+                                        "substring expects (string start end), found {} parameters",
+                                        tail.len()
+                                    ),
+                                },
+                                // Format through `Expr`'s `Display` except for
+                                // whole-number floats, where `f64`'s `Display`
+                                // drops the fractional part and would make
+                                // `(to-string 3.0)` indistinguishable from
+                                // `(to-string 3)`.
+                                BuiltIn::ToString => match car(&tail) {
+                                    Some(Expr::Constant(Atom::Float(it)))
+                                        if it.fract() == 0.0 && it.is_finite() =>
+                                    {
+                                        string_to_expr(format!("{it:.1}"))
+                                    }
+                                    Some(it) => string_to_expr(format!("{it}")),
+                                    None => bail!("to-string expects 1 parameter, got 0"),
+                                },
                             })
                         }
                         it => return Ok(it),
@@ -0,0 +1,101 @@
+mod error;
+mod eval;
+mod parse;
+
+pub use error::Error;
+pub use eval::Context;
+pub use parse::{parse, type_of, Atom, BuiltIn, Expr, ValueType};
+
+use fehler::throws;
+
+#[throws]
+pub fn parse_and_eval(input: &str, context: &mut Context) -> Vec<Expr> {
+    parse(input)
+        .map_err(|e| Error::Message(e.to_string()))?
+        .into_iter()
+        .map(|expr| context.eval(expr))
+        .collect::<Result<Vec<_>, _>>()?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn define_supports_self_recursion() {
+        let mut context = Context::default();
+        let results = parse_and_eval(
+            "(define fact (n) (if (= n 0) 1 (* n (fact (- n 1))))) (fact 5)",
+            &mut context,
+        )
+        .unwrap();
+        assert_eq!(results.last(), Some(&Expr::Constant(Atom::Number(120))));
+    }
+
+    // `fact` above recurses from inside `*`'s argument list, so it never
+    // rides the `expr = body; continue` trampoline (see the comment above
+    // `parse_define`) and would blow the Rust stack long before this depth.
+    // An accumulator-passing rewrite puts the self-call in tail position, so
+    // this only passes if tail calls genuinely reuse the stack frame - if
+    // the trampoline regresses, this overflows the Rust stack instead of
+    // returning. `acc` starts as a float so the running product promotes to
+    // `Num::Float` immediately and saturates towards infinity instead of
+    // panicking on `i32` overflow well before 2,000,000 iterations.
+    #[test]
+    fn tail_recursive_define_does_not_grow_the_stack() {
+        let mut context = Context::default();
+        let results = parse_and_eval(
+            "(define fact-iter (n acc) (if (= n 0) acc (fact-iter (- n 1) (* n acc)))) (fact-iter 2000000 1.0)",
+            &mut context,
+        )
+        .unwrap();
+        assert!(matches!(results.last(), Some(Expr::Constant(Atom::Float(_)))));
+    }
+
+    #[test]
+    fn arithmetic_promotes_to_float_when_mixed() {
+        let mut context = Context::default();
+        let results = parse_and_eval("(+ 1 2.5)", &mut context).unwrap();
+        assert_eq!(results.last(), Some(&Expr::Constant(Atom::Float(3.5))));
+    }
+
+    #[test]
+    fn equal_compares_numerically_across_int_and_float() {
+        let mut context = Context::default();
+        let results = parse_and_eval("(= 2 2.0)", &mut context).unwrap();
+        assert_eq!(
+            results.last(),
+            Some(&Expr::Constant(Atom::Symbol("T".to_string())))
+        );
+    }
+
+    #[test]
+    fn string_escapes_round_trip() {
+        let mut context = Context::default();
+        let results = parse_and_eval(r#""a\"b\\c\nd\te""#, &mut context).unwrap();
+        assert_eq!(
+            results.last(),
+            Some(&Expr::Constant(Atom::String("a\"b\\c\nd\te".to_string())))
+        );
+    }
+
+    #[test]
+    fn substring_truncates_float_indices() {
+        let mut context = Context::default();
+        let results = parse_and_eval(r#"(substring "hello" 1.9 3)"#, &mut context).unwrap();
+        assert_eq!(
+            results.last(),
+            Some(&Expr::Constant(Atom::String("el".to_string())))
+        );
+    }
+
+    #[test]
+    fn substring_clamps_negative_and_out_of_range_indices() {
+        let mut context = Context::default();
+        let results = parse_and_eval(r#"(substring "hello" -1 100)"#, &mut context).unwrap();
+        assert_eq!(
+            results.last(),
+            Some(&Expr::Constant(Atom::String("hello".to_string())))
+        );
+    }
+}
@@ -0,0 +1,400 @@
+use std::fmt;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{escaped_transform, is_not},
+    character::complete::{alphanumeric1, char, digit1, multispace0, multispace1, one_of},
+    combinator::{cut, map, map_res, opt, recognize, value},
+    multi::{many0, many1},
+    sequence::{delimited, pair, preceded, terminated, tuple},
+    Parser,
+};
+use nom_supreme::{
+    error::ErrorTree, final_parser::final_parser, tag::complete::tag, ParserExt,
+};
+
+// Helpers
+type IResult<'a, T, U> = nom::IResult<T, U, ErrorTree<&'a str>>;
+
+fn sexp<'a, O1, F>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O1>
+where
+    F: Parser<&'a str, O1, ErrorTree<&'a str>>,
+{
+    delimited(
+        char('('),
+        preceded(multispace0, inner),
+        cut(preceded(multispace0, char(')'))),
+    )
+}
+
+// Atoms
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BuiltIn {
+    Plus,
+    Minus,
+    Times,
+    Divide,
+    Greater,
+    Less,
+    GreaterEqual,
+    LessEqual,
+    Equal,
+    NotEqual,
+    And,
+    Or,
+    Not,
+    Concat,
+    Length,
+    Substring,
+    ToString,
+}
+
+impl fmt::Display for BuiltIn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let it = match self {
+            BuiltIn::Plus => "+",
+            BuiltIn::Minus => "-",
+            BuiltIn::Times => "*",
+            BuiltIn::Divide => "/",
+            BuiltIn::Greater => ">",
+            BuiltIn::Less => "<",
+            BuiltIn::GreaterEqual => ">=",
+            BuiltIn::LessEqual => "<=",
+            BuiltIn::Equal => "=",
+            BuiltIn::NotEqual => "!=",
+            BuiltIn::And => "and",
+            BuiltIn::Or => "or",
+            BuiltIn::Not => "not",
+            BuiltIn::Concat => "concat",
+            BuiltIn::Length => "length",
+            BuiltIn::Substring => "substring",
+            BuiltIn::ToString => "to-string",
+        };
+        write!(f, "{it}")
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Atom {
+    Number(i32),
+    Float(f64),
+    String(String),
+    Symbol(String),
+    BuiltIn(BuiltIn),
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Atom::Number(it) => write!(f, "{it}"),
+            Atom::Float(it) => write!(f, "{it}"),
+            Atom::String(it) => write!(f, "{it}"),
+            Atom::Symbol(it) => write!(f, "{it}"),
+            Atom::BuiltIn(it) => write!(f, "{it}"),
+        }
+    }
+}
+
+fn parse_built_in(input: &str) -> IResult<&str, Atom> {
+    map(
+        alt((
+            map(tag(">="), |_| BuiltIn::GreaterEqual),
+            map(tag("<="), |_| BuiltIn::LessEqual),
+            map(tag("!="), |_| BuiltIn::NotEqual),
+            map(tag("+"), |_| BuiltIn::Plus),
+            map(tag("-"), |_| BuiltIn::Minus),
+            map(tag("*"), |_| BuiltIn::Times),
+            map(tag("/"), |_| BuiltIn::Divide),
+            map(tag(">"), |_| BuiltIn::Greater),
+            map(tag("<"), |_| BuiltIn::Less),
+            map(tag("="), |_| BuiltIn::Equal),
+            map(tag("and"), |_| BuiltIn::And),
+            map(tag("or"), |_| BuiltIn::Or),
+            map(tag("not"), |_| BuiltIn::Not),
+            map(tag("concat"), |_| BuiltIn::Concat),
+            map(tag("length"), |_| BuiltIn::Length),
+            map(tag("substring"), |_| BuiltIn::Substring),
+            map(alt((tag("to-string"), tag("number->string"))), |_| {
+                BuiltIn::ToString
+            }),
+        ))
+        .context("operator"),
+        Atom::BuiltIn,
+    )(input)
+}
+
+// Modeled on askama's `escaped`-based string lexer: `\` escapes `"`, `\`,
+// `n` and `t`, decoding them into the stored value as it parses.
+fn parse_string(input: &str) -> IResult<&str, Atom> {
+    map(
+        delimited(
+            char('"'),
+            opt(escaped_transform(
+                is_not("\"\\"),
+                '\\',
+                alt((
+                    value("\"", tag("\"")),
+                    value("\\", tag("\\")),
+                    value("\n", tag("n")),
+                    value("\t", tag("t")),
+                )),
+            )),
+            cut(char('"')),
+        )
+        .context("string"),
+        |body: Option<String>| Atom::String(body.unwrap_or_default()),
+    )(input)
+}
+
+// Try float first when the literal has a `.` or exponent, otherwise fall
+// back to a plain i32 so integer arithmetic stays exact.
+fn parse_number(input: &str) -> IResult<&str, Atom> {
+    map_res(
+        recognize(tuple((
+            opt(char('-')),
+            digit1,
+            opt(pair(char('.'), digit1)),
+            opt(tuple((one_of("eE"), opt(one_of("+-")), digit1))),
+        ))),
+        |digits: &str| -> Result<Atom, std::num::ParseIntError> {
+            if digits.contains('.') || digits.contains(['e', 'E']) {
+                Ok(Atom::Float(
+                    digits.parse::<f64>().expect("grammar guarantees a valid float"),
+                ))
+            } else {
+                digits.parse::<i32>().map(Atom::Number)
+            }
+        },
+    )
+    .context("number")
+    .parse(input)
+}
+
+fn parse_symbol(input: &str) -> IResult<&str, Atom> {
+    map(
+        recognize(many1(alt((alphanumeric1, tag("-"), tag("?"), tag("!"), tag("*")))))
+            .context("symbol"),
+        |symbol: &str| Atom::Symbol(symbol.to_string()),
+    )(input)
+}
+
+fn parse_atom(input: &str) -> IResult<&str, Atom> {
+    alt((parse_number, parse_string, parse_built_in, parse_symbol))(input)
+}
+
+// Expressions
+#[derive(Debug, PartialEq, Clone)]
+pub enum Expr {
+    Constant(Atom),
+    Nil,
+    /// (func-name arg1 arg2)
+    Call(Box<Expr>, Vec<Expr>),
+    /// (if predicate then [otherwise])
+    If(Box<Expr>, Box<Expr>, Option<Box<Expr>>),
+    /// (let ((name value) ...))
+    Let(Vec<(Atom, Box<Expr>)>),
+    /// (lambda (arg1 arg2) body)
+    Function(Vec<Expr>, Box<Expr>),
+    /// '(3 (if (+ 3 3) 4 5) 7)
+    Quote(Vec<Expr>),
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Constant(atom) => write!(f, "{atom}"),
+            Expr::Nil => write!(f, "nil"),
+            Expr::Call(head, tail) => {
+                write!(f, "({head}")?;
+                for item in tail {
+                    write!(f, " {item}")?;
+                }
+                write!(f, ")")
+            }
+            Expr::If(predicate, then, Some(otherwise)) => {
+                write!(f, "(if {predicate} {then} {otherwise})")
+            }
+            Expr::If(predicate, then, None) => write!(f, "(if {predicate} {then})"),
+            Expr::Let(items) => {
+                write!(f, "(let")?;
+                for (name, value) in items {
+                    write!(f, " ({name} {value})")?;
+                }
+                write!(f, ")")
+            }
+            Expr::Function(args, body) => {
+                write!(f, "(lambda (")?;
+                for (index, arg) in args.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{arg}")?;
+                }
+                write!(f, ") {body})")
+            }
+            Expr::Quote(items) => {
+                write!(f, "'(")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+// The kind of value an `Expr` evaluates to, used to report structured
+// type errors instead of ad-hoc strings (see `Error::TypeMismatch`).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ValueType {
+    Number,
+    Boolean,
+    Symbol,
+    Function,
+    Quote,
+    Nil,
+    String,
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let it = match self {
+            ValueType::Number => "Number",
+            ValueType::Boolean => "Boolean",
+            ValueType::Symbol => "Symbol",
+            ValueType::Function => "Function",
+            ValueType::Quote => "Quote",
+            ValueType::Nil => "Nil",
+            ValueType::String => "String",
+        };
+        write!(f, "{it}")
+    }
+}
+
+pub fn type_of(expr: &Expr) -> ValueType {
+    match expr {
+        Expr::Constant(Atom::Number(_) | Atom::Float(_)) => ValueType::Number,
+        Expr::Constant(Atom::String(_)) => ValueType::String,
+        Expr::Constant(Atom::Symbol(it)) if it == "T" => ValueType::Boolean,
+        Expr::Constant(Atom::Symbol(_)) => ValueType::Symbol,
+        Expr::Constant(Atom::BuiltIn(_)) => ValueType::Function,
+        Expr::Function(..) => ValueType::Function,
+        Expr::Quote(_) => ValueType::Quote,
+        // `boolean_to_expr(false)` produces `Expr::Nil` (there's no dedicated
+        // "false" atom - see `eval::boolean_to_expr`), so this has to agree
+        // with the `Atom::Symbol("T")` arm above or `TypeMismatch` reports a
+        // different `ValueType` depending on which side of a comparison
+        // lied. The tradeoff: a `let`'s "no value" result reads back as
+        // `ValueType::Boolean` too, since it also evaluates to `Expr::Nil`.
+        Expr::Nil => ValueType::Boolean,
+        Expr::Call(..) | Expr::If(..) | Expr::Let(..) => ValueType::Symbol,
+    }
+}
+
+fn parse_constant(input: &str) -> IResult<&str, Expr> {
+    map(parse_atom, Expr::Constant)(input)
+}
+
+fn parse_call(input: &str) -> IResult<&str, Expr> {
+    sexp(map(tuple((parse_expr, many0(parse_expr))), |(head, tail)| {
+        Expr::Call(Box::new(head), tail)
+    }))(input)
+}
+
+fn parse_if(input: &str) -> IResult<&str, Expr> {
+    sexp(map(
+        preceded(
+            terminated(tag("if"), multispace1),
+            cut(tuple((parse_expr, parse_expr, opt(parse_expr)))),
+        ),
+        |(predicate, then, otherwise)| {
+            Expr::If(Box::new(predicate), Box::new(then), otherwise.map(Box::new))
+        },
+    ))(input)
+}
+
+fn parse_binding(input: &str) -> IResult<&str, (Atom, Box<Expr>)> {
+    sexp(map(
+        tuple((parse_atom, preceded(multispace1, parse_expr))),
+        |(name, value)| (name, Box::new(value)),
+    ))(input)
+}
+
+fn parse_let(input: &str) -> IResult<&str, Expr> {
+    sexp(map(
+        preceded(
+            terminated(tag("let"), multispace1),
+            cut(sexp(many0(preceded(multispace0, parse_binding)))),
+        ),
+        Expr::Let,
+    ))(input)
+}
+
+fn parse_function(input: &str) -> IResult<&str, Expr> {
+    sexp(map(
+        preceded(
+            terminated(tag("lambda"), multispace1),
+            cut(tuple((
+                sexp(many0(preceded(multispace0, parse_constant))),
+                preceded(multispace0, parse_expr),
+            ))),
+        ),
+        |(args, body)| Expr::Function(args, Box::new(body)),
+    ))(input)
+}
+
+fn parse_quote(input: &str) -> IResult<&str, Expr> {
+    map(preceded(tag("'"), cut(sexp(many0(parse_expr)))), Expr::Quote)(input)
+}
+
+// `(define name (args...) body)` is sugar for binding a `lambda` under
+// `name` with `let`. Because `Context::eval` only resolves a symbol's
+// value by looking it up in `environment` when it's *called*, and `let`
+// already inserts before returning, `name` is resolvable from inside its
+// own `body` by the time any recursive call runs - self-recursion falls
+// out of the existing machinery without a dedicated `Expr` variant.
+//
+// This does *not* make every recursive `define` stack-safe: the existing
+// `expr = body; continue;` trampoline only avoids Rust recursion when the
+// self-call is itself the tail expression. `(define fact (n) (if (= n 0)
+// 1 (* n (fact (- n 1)))))` calls `fact` from inside `*`'s argument list,
+// so each level still needs a real `Context::eval` stack frame to produce
+// a value before `*` can run, and deep enough `n` will overflow the stack
+// like any other non-tail-recursive call. Only a self-call that is
+// literally the last expression evaluated (e.g. an accumulator-passing
+// rewrite of `fact`) rides the trampoline without growing the stack.
+fn parse_define(input: &str) -> IResult<&str, Expr> {
+    sexp(map(
+        preceded(
+            terminated(tag("define"), multispace1),
+            cut(tuple((
+                parse_symbol,
+                preceded(multispace0, sexp(many0(preceded(multispace0, parse_constant)))),
+                preceded(multispace0, parse_expr),
+            ))),
+        ),
+        |(name, args, body)| Expr::Let(vec![(name, Box::new(Expr::Function(args, Box::new(body))))]),
+    ))(input)
+}
+
+fn parse_expr(input: &str) -> IResult<&str, Expr> {
+    preceded(
+        multispace0,
+        alt((
+            parse_quote,
+            parse_if,
+            parse_let,
+            parse_define,
+            parse_function,
+            parse_call,
+            parse_constant,
+        )),
+    )(input)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Expr>, ErrorTree<&str>> {
+    final_parser(many1(delimited(multispace0, parse_expr, multispace0)))(input)
+}
@@ -1,7 +1,19 @@
 use rustyline::error::ReadlineError;
 use rustyline::Editor;
 
+fn dump_ast(input: &str) {
+    match lisp::parse(input) {
+        Ok(exprs) => println!("{:#?}", exprs),
+        Err(e) => println!("Error occurred: {}", e),
+    }
+}
+
 fn main() {
+    // Following boa's `-t`/`-a` debug flags: `-a`/`--ast` starts the REPL
+    // already dumping the parsed `Vec<Expr>` for every line instead of
+    // evaluating it.
+    let mut ast_mode = std::env::args().any(|it| it == "-a" || it == "--ast");
+
     let mut rl = Editor::<()>::new();
     let mut context = lisp::Context::default();
     loop {
@@ -9,6 +21,26 @@ fn main() {
         match readline {
             Ok(line) => {
                 rl.add_history_entry(line.as_str());
+
+                // `:ast`/`:parse <expr>` dump that expression's AST once;
+                // bare `:ast`/`:parse` toggles the mode for every line after.
+                if let Some(rest) = line
+                    .strip_prefix(":ast ")
+                    .or_else(|| line.strip_prefix(":parse "))
+                {
+                    dump_ast(rest);
+                    continue;
+                } else if line.trim() == ":ast" || line.trim() == ":parse" {
+                    ast_mode = !ast_mode;
+                    println!("ast mode: {}", if ast_mode { "on" } else { "off" });
+                    continue;
+                }
+
+                if ast_mode {
+                    dump_ast(&line);
+                    continue;
+                }
+
                 match lisp::parse_and_eval(&line, &mut context) {
                     Ok(expr) => {
                         if expr.len() > 0 {
@@ -0,0 +1,77 @@
+//! Browser playground for the `lisp` interpreter, built on `egui`/`eframe`.
+//!
+//! This mirrors the rustyline REPL in `repl`: a persistent `lisp::Context`
+//! is fed one input at a time through `parse_and_eval`, with results (or
+//! `Error occurred:` messages) appended to a scrolling output pane. The
+//! same `App::update` drives both a native window and the wasm32 target,
+//! so `Context::eval` itself stays untouched.
+
+struct App {
+    context: lisp::Context,
+    input: String,
+    output: String,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self {
+            context: lisp::Context::default(),
+            input: String::new(),
+            output: String::new(),
+        }
+    }
+}
+
+impl eframe::App for App {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("lisp playground");
+            ui.add(
+                egui::TextEdit::multiline(&mut self.input)
+                    .code_editor()
+                    .desired_rows(8)
+                    .desired_width(f32::INFINITY),
+            );
+            if ui.button("Run").clicked() {
+                let result = match lisp::parse_and_eval(&self.input, &mut self.context) {
+                    Ok(expr) => format!("{:#?}", expr),
+                    Err(e) => format!("Error occurred: {}", e),
+                };
+                self.output
+                    .push_str(&format!(">> {}\n{}\n", self.input, result));
+            }
+            ui.separator();
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.monospace(&self.output);
+            });
+        });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    let options = eframe::NativeOptions::default();
+    eframe::run_native(
+        "lisp playground",
+        options,
+        Box::new(|_cc| Box::new(App::default())),
+    )
+    .expect("failed to launch native window");
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    eframe::WebLogger::init(log::LevelFilter::Debug).ok();
+
+    let web_options = eframe::WebOptions::default();
+    wasm_bindgen_futures::spawn_local(async {
+        eframe::WebRunner::new()
+            .start(
+                "canvas",
+                web_options,
+                Box::new(|_cc| Box::new(App::default())),
+            )
+            .await
+            .expect("failed to start eframe");
+    });
+}